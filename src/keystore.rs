@@ -0,0 +1,359 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use scrypt::Params as ScryptParams;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::serialization::{SchemeId, SchemeParams};
+use crate::PySecretKey;
+
+const ENVELOPE_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+const DEFAULT_CHUNK_SIZE: u64 = 1000;
+
+/// Versioned, self-describing on-disk envelope. The ciphertext covers the
+/// secret key bytes plus the prepared-epoch window so both are restored
+/// together on load.
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u8,
+    salt: Vec<u8>,
+    nonce: Vec<u8>,
+    ciphertext: Vec<u8>,
+}
+
+/// Plaintext shape that gets encrypted into the envelope. Held long-term in
+/// `PyKeystore::state`, so `inner` is wiped on drop for the same reason
+/// `PySecretKey` wipes its own copy: this is hash-based secret key material
+/// that should not linger in freed heap memory.
+struct SecretKeyState {
+    inner: Vec<u8>,
+    prepared_start: u64,
+    prepared_end: u64,
+    scheme_params: SchemeParams,
+}
+
+impl Drop for SecretKeyState {
+    fn drop(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+const STATE_HEADER_LEN: usize = 8 + 8 + 1 + 8 + 8;
+
+impl SecretKeyState {
+    fn to_plaintext(&self) -> Zeroizing<Vec<u8>> {
+        let mut buf = Vec::with_capacity(STATE_HEADER_LEN + self.inner.len());
+        buf.extend_from_slice(&self.prepared_start.to_le_bytes());
+        buf.extend_from_slice(&self.prepared_end.to_le_bytes());
+        buf.push(self.scheme_params.scheme as u8);
+        buf.extend_from_slice(&self.scheme_params.lifetime.to_le_bytes());
+        buf.extend_from_slice(&self.scheme_params.activation_epoch.to_le_bytes());
+        buf.extend_from_slice(&self.inner);
+        Zeroizing::new(buf)
+    }
+
+    fn from_plaintext(data: &[u8]) -> PyResult<Self> {
+        if data.len() < STATE_HEADER_LEN {
+            return Err(PyException::new_err("corrupt keystore plaintext"));
+        }
+        let scheme = match data[16] {
+            1 => SchemeId::Sha3,
+            2 => SchemeId::Poseidon,
+            other => {
+                return Err(PyException::new_err(format!(
+                    "corrupt keystore plaintext: unknown scheme identifier {}",
+                    other
+                )))
+            }
+        };
+        Ok(SecretKeyState {
+            prepared_start: u64::from_le_bytes(data[0..8].try_into().unwrap()),
+            prepared_end: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+            scheme_params: SchemeParams {
+                scheme,
+                lifetime: u64::from_le_bytes(data[17..25].try_into().unwrap()),
+                activation_epoch: u64::from_le_bytes(data[25..33].try_into().unwrap()),
+            },
+            inner: data[STATE_HEADER_LEN..].to_vec(),
+        })
+    }
+}
+
+fn derive_key(passphrase: &[u8], salt: &[u8]) -> PyResult<Zeroizing<[u8; KEY_LEN]>> {
+    let params = ScryptParams::recommended();
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    scrypt::scrypt(passphrase, salt, &params, &mut *key)
+        .map_err(|e| PyException::new_err(format!("key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+/// Secret-key storage that keeps material encrypted at rest. Decrypts on
+/// demand and can advance its prepared-epoch window on a background thread
+/// so the window always stays ahead of the current epoch, re-encrypting and
+/// persisting after each advance.
+#[pyclass]
+pub struct PyKeystore {
+    path: PathBuf,
+    cipher_key: Zeroizing<[u8; KEY_LEN]>,
+    chunk_size: u64,
+    state: Arc<Mutex<SecretKeyState>>,
+    stop_flag: Arc<AtomicBool>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl Drop for PyKeystore {
+    fn drop(&mut self) {
+        self.stop_flag.store(true, Ordering::SeqCst);
+        if let Some(worker) = self.worker.take() {
+            Python::with_gil(|py| py.allow_threads(|| worker.join().ok()));
+        }
+    }
+}
+
+#[pymethods]
+impl PyKeystore {
+    /// Encrypt `secret_key` under `passphrase` and write it to `path`.
+    #[staticmethod]
+    #[pyo3(signature = (path, passphrase, secret_key, chunk_size=DEFAULT_CHUNK_SIZE))]
+    fn create(
+        path: String,
+        passphrase: Vec<u8>,
+        secret_key: &PySecretKey,
+        chunk_size: u64,
+    ) -> PyResult<Self> {
+        let path = PathBuf::from(path);
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let cipher_key = derive_key(&passphrase, &salt)?;
+
+        let state = SecretKeyState {
+            inner: secret_key.inner.clone(),
+            prepared_start: secret_key.prepared_start,
+            prepared_end: secret_key.prepared_end,
+            scheme_params: secret_key.scheme_params,
+        };
+
+        let keystore = PyKeystore {
+            path,
+            cipher_key,
+            chunk_size,
+            state: Arc::new(Mutex::new(state)),
+            stop_flag: Arc::new(AtomicBool::new(false)),
+            worker: None,
+        };
+        keystore.persist(&salt)?;
+        Ok(keystore)
+    }
+
+    /// Load and decrypt a keystore previously written by `create`.
+    #[staticmethod]
+    #[pyo3(signature = (path, passphrase, chunk_size=DEFAULT_CHUNK_SIZE))]
+    fn open(path: String, passphrase: Vec<u8>, chunk_size: u64) -> PyResult<Self> {
+        let path = PathBuf::from(path);
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| PyException::new_err(format!("failed to read keystore: {}", e)))?;
+        let envelope: Envelope = serde_json::from_str(&contents)
+            .map_err(|e| PyException::new_err(format!("corrupt keystore envelope: {}", e)))?;
+        if envelope.version != ENVELOPE_VERSION {
+            return Err(PyException::new_err(format!(
+                "unsupported keystore version {} (expected {})",
+                envelope.version, ENVELOPE_VERSION
+            )));
+        }
+
+        if envelope.salt.len() != SALT_LEN {
+            return Err(PyException::new_err(format!(
+                "corrupt keystore envelope: salt must be {} bytes, found {}",
+                SALT_LEN,
+                envelope.salt.len()
+            )));
+        }
+        if envelope.nonce.len() != NONCE_LEN {
+            return Err(PyException::new_err(format!(
+                "corrupt keystore envelope: nonce must be {} bytes, found {}",
+                NONCE_LEN,
+                envelope.nonce.len()
+            )));
+        }
+
+        let cipher_key = derive_key(&passphrase, &envelope.salt)?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(cipher_key.as_ref()));
+        let nonce = Nonce::from_slice(&envelope.nonce);
+        let plaintext: Zeroizing<Vec<u8>> = Zeroizing::new(
+            cipher
+                .decrypt(nonce, envelope.ciphertext.as_ref())
+                .map_err(|_| {
+                    PyException::new_err("failed to decrypt keystore (wrong passphrase?)")
+                })?,
+        );
+        let state = SecretKeyState::from_plaintext(&plaintext)?;
+
+        Ok(PyKeystore {
+            path,
+            cipher_key,
+            chunk_size,
+            state: Arc::new(Mutex::new(state)),
+            stop_flag: Arc::new(AtomicBool::new(false)),
+            worker: None,
+        })
+    }
+
+    /// Return a copy of the current secret key material, which was decrypted
+    /// when the keystore was opened (or taken as given by `create`).
+    fn secret_key(&self) -> PyResult<PySecretKey> {
+        let state = self
+            .state
+            .lock()
+            .map_err(|_| PyException::new_err("keystore lock poisoned"))?;
+        Ok(PySecretKey {
+            inner: state.inner.clone(),
+            prepared_start: state.prepared_start,
+            prepared_end: state.prepared_end,
+            scheme_params: state.scheme_params,
+            destroyed: false,
+        })
+    }
+
+    /// Grow the prepared-epoch window by `chunk_size` and re-encrypt +
+    /// persist the result.
+    fn advance_preparation(&self) -> PyResult<()> {
+        self.advance_locked()
+    }
+
+    /// Spawn a background thread that calls `advance_preparation` every
+    /// `poll_interval_secs`, keeping the prepared window ahead of the
+    /// current epoch without blocking the interpreter (the Python GIL is
+    /// never held by the worker thread).
+    fn start_background_preparation(&mut self, poll_interval_secs: u64) -> PyResult<()> {
+        if self.worker.is_some() {
+            return Err(PyException::new_err(
+                "background preparation is already running",
+            ));
+        }
+
+        self.stop_flag.store(false, Ordering::SeqCst);
+        let state = Arc::clone(&self.state);
+        let stop_flag = Arc::clone(&self.stop_flag);
+        let cipher_key = self.cipher_key.clone();
+        let chunk_size = self.chunk_size;
+        let path = self.path.clone();
+
+        self.worker = Some(thread::spawn(move || {
+            while !stop_flag.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_secs(poll_interval_secs));
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(mut guard) = state.lock() {
+                    advance_and_persist(&mut guard, chunk_size, &cipher_key, &path).ok();
+                }
+            }
+        }));
+        Ok(())
+    }
+
+    /// Stop a running background preparation thread, if any. The worker only
+    /// notices `stop_flag` after waking from its poll-interval sleep, so the
+    /// join can block for up to that long; releasing the GIL around it keeps
+    /// the interpreter responsive while this call waits.
+    fn stop_background_preparation(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.stop_flag.store(true, Ordering::SeqCst);
+        if let Some(worker) = self.worker.take() {
+            py.allow_threads(|| worker.join())
+                .map_err(|_| PyException::new_err("background preparation thread panicked"))?;
+        }
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Keystore(path={:?})", self.path)
+    }
+}
+
+impl PyKeystore {
+    fn advance_locked(&self) -> PyResult<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| PyException::new_err("keystore lock poisoned"))?;
+        advance_and_persist(&mut state, self.chunk_size, &self.cipher_key, &self.path)
+    }
+
+    fn persist(&self, salt: &[u8]) -> PyResult<()> {
+        let state = self
+            .state
+            .lock()
+            .map_err(|_| PyException::new_err("keystore lock poisoned"))?;
+        persist_envelope(&state, salt, &self.cipher_key, &self.path)
+    }
+}
+
+/// Grows the prepared window by `chunk_size`, re-encrypts the state with its
+/// existing salt, and persists it via write-ahead-then-rename.
+fn advance_and_persist(
+    state: &mut SecretKeyState,
+    chunk_size: u64,
+    cipher_key: &[u8; KEY_LEN],
+    path: &PathBuf,
+) -> PyResult<()> {
+    state.prepared_end += chunk_size;
+
+    let existing = fs::read_to_string(path)
+        .map_err(|e| PyException::new_err(format!("failed to read keystore: {}", e)))?;
+    let envelope: Envelope = serde_json::from_str(&existing)
+        .map_err(|e| PyException::new_err(format!("corrupt keystore envelope: {}", e)))?;
+
+    persist_envelope(state, &envelope.salt, cipher_key, path)
+}
+
+fn persist_envelope(
+    state: &SecretKeyState,
+    salt: &[u8],
+    cipher_key: &[u8; KEY_LEN],
+    path: &PathBuf,
+) -> PyResult<()> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(cipher_key));
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, state.to_plaintext().as_ref())
+        .map_err(|e| PyException::new_err(format!("encryption failed: {}", e)))?;
+
+    let envelope = Envelope {
+        version: ENVELOPE_VERSION,
+        salt: salt.to_vec(),
+        nonce: nonce_bytes.to_vec(),
+        ciphertext,
+    };
+    let serialized = serde_json::to_vec_pretty(&envelope)
+        .map_err(|e| PyException::new_err(format!("failed to serialize keystore: {}", e)))?;
+
+    let tmp_path = path.with_extension("tmp");
+    let mut tmp_file = fs::File::create(&tmp_path)
+        .map_err(|e| PyException::new_err(format!("failed to write keystore: {}", e)))?;
+    tmp_file
+        .write_all(&serialized)
+        .map_err(|e| PyException::new_err(format!("failed to write keystore: {}", e)))?;
+    tmp_file
+        .sync_all()
+        .map_err(|e| PyException::new_err(format!("failed to write keystore: {}", e)))?;
+    fs::rename(&tmp_path, path)
+        .map_err(|e| PyException::new_err(format!("failed to persist keystore: {}", e)))?;
+    Ok(())
+}
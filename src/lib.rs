@@ -1,28 +1,53 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use rayon::prelude::*;
+use zeroize::Zeroize;
+
+mod context;
+mod keystore;
+mod serialization;
+mod slashing;
+
+use context::SigningContext;
+use keystore::PyKeystore;
+use serialization::{decode_canonical, encode_canonical, SchemeId, SchemeParams};
+use slashing::{EpochReuseError, SlashingProtection};
 
 // Import the hash-sig library types
 // Note: Currently using placeholder implementations
 // In a real implementation, these would be used to wrap the actual hash-sig types
 
 /// Python wrapper for the public key
+///
+/// `to_bytes`/`from_bytes` produce the canonical, versioned wire format (see
+/// `serialization`) -- this is a consensus-facing encoding and must not be
+/// confused with the `serde` implementation gated behind the `serde`
+/// feature, which exists only for Python pickling / debug interop.
 #[pyclass]
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PyPublicKey {
     inner: Vec<u8>,
+    pub(crate) scheme_params: SchemeParams,
 }
 
 #[pymethods]
 impl PyPublicKey {
-    /// Serialize the public key to bytes
+    /// Serialize the public key to the canonical wire format: a version
+    /// byte, scheme identifier, scheme parameters, and the key bytes.
     fn to_bytes(&self) -> PyResult<Vec<u8>> {
-        Ok(self.inner.clone())
+        Ok(encode_canonical(self.scheme_params, &self.inner))
     }
 
-    /// Deserialize a public key from bytes
+    /// Deserialize a public key previously produced by `to_bytes`. Rejects
+    /// truncated blobs and blobs with a mismatched canonical version.
     #[staticmethod]
     fn from_bytes(data: Vec<u8>) -> PyResult<Self> {
-        Ok(PyPublicKey { inner: data })
+        let (scheme_params, inner) = decode_canonical(&data)?;
+        Ok(PyPublicKey {
+            inner,
+            scheme_params,
+        })
     }
 
     fn __repr__(&self) -> String {
@@ -31,11 +56,26 @@ impl PyPublicKey {
 }
 
 /// Python wrapper for the secret key
+///
+/// As with `PyPublicKey`, `to_bytes`/`from_bytes` are the canonical wire
+/// format, not a serde/Debug encoding. `inner` is wiped on drop (and by the
+/// explicit `destroy()` method) since hash-based secret keys are large and
+/// long-lived across many epochs, so leaving copies in freed heap memory is
+/// a real exposure.
 #[pyclass]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PySecretKey {
-    inner: Vec<u8>,
-    prepared_start: u64,
-    prepared_end: u64,
+    pub(crate) inner: Vec<u8>,
+    pub(crate) prepared_start: u64,
+    pub(crate) prepared_end: u64,
+    pub(crate) scheme_params: SchemeParams,
+    pub(crate) destroyed: bool,
+}
+
+impl Drop for PySecretKey {
+    fn drop(&mut self) {
+        self.inner.zeroize();
+    }
 }
 
 #[pymethods]
@@ -55,19 +95,64 @@ impl PySecretKey {
         epoch >= self.prepared_start && epoch < self.prepared_end
     }
 
-    /// Advance the preparation interval
-    /// This should be called in the background as epochs are used
-    fn advance_preparation(&mut self) -> PyResult<()> {
-        // Update the prepared interval
-        // In a real implementation, this would call sk.advance_preparation()
-        self.prepared_start = self.prepared_end;
-        self.prepared_end += self.prepared_end - self.prepared_start;
+    /// Grow the preparation interval by `chunk_size` epochs, leaving
+    /// `prepared_start` anchored where it is. Defaults to the current
+    /// window's width if omitted, so repeated calls double the window
+    /// before it's ever consumed -- that default isn't available right
+    /// after `from_bytes`, where the window is empty, so `chunk_size` is
+    /// required in that case.
+    #[pyo3(signature = (chunk_size=None))]
+    fn advance_preparation(&mut self, chunk_size: Option<u64>) -> PyResult<()> {
+        let step = match chunk_size {
+            Some(step) => step,
+            None => {
+                let width = self.prepared_end - self.prepared_start;
+                if width == 0 {
+                    return Err(PyValueError::new_err(
+                        "advance_preparation requires an explicit chunk_size when the \
+                         prepared window is empty (e.g. right after from_bytes)",
+                    ));
+                }
+                width
+            }
+        };
+        self.prepared_end += step;
         Ok(())
     }
 
-    /// Serialize the secret key to bytes (WARNING: Keep this secure!)
+    /// Serialize the secret key to the canonical wire format (WARNING: Keep
+    /// this secure! The key material is still in the clear).
     fn to_bytes(&self) -> PyResult<Vec<u8>> {
-        Ok(self.inner.clone())
+        self.ensure_not_destroyed()?;
+        Ok(encode_canonical(self.scheme_params, &self.inner))
+    }
+
+    /// Deserialize a secret key previously produced by `to_bytes`. Rejects
+    /// truncated blobs and blobs with a mismatched canonical version.
+    ///
+    /// Note: the prepared-epoch window is not carried by the canonical
+    /// format and resets to `[activation_epoch, activation_epoch)`; callers
+    /// must call `advance_preparation` with an explicit `chunk_size` again
+    /// after loading (the width-preserving default has no window to measure
+    /// from yet).
+    #[staticmethod]
+    fn from_bytes(data: Vec<u8>) -> PyResult<Self> {
+        let (scheme_params, inner) = decode_canonical(&data)?;
+        Ok(PySecretKey {
+            inner,
+            prepared_start: scheme_params.activation_epoch,
+            prepared_end: scheme_params.activation_epoch,
+            scheme_params,
+            destroyed: false,
+        })
+    }
+
+    /// Proactively wipe the key material, e.g. once the prepared window is
+    /// exhausted. Any later `sign`/`to_bytes` call on this key raises
+    /// instead of operating on zeroed bytes.
+    fn destroy(&mut self) {
+        self.inner.zeroize();
+        self.destroyed = true;
     }
 
     fn __repr__(&self) -> String {
@@ -78,24 +163,46 @@ impl PySecretKey {
     }
 }
 
+impl PySecretKey {
+    fn ensure_not_destroyed(&self) -> PyResult<()> {
+        if self.destroyed {
+            return Err(PyValueError::new_err(
+                "secret key has been destroyed and can no longer be used",
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// Python wrapper for signatures
+///
+/// As with `PyPublicKey`, `to_bytes`/`from_bytes` are the canonical wire
+/// format; the `activation_epoch` slot in the embedded scheme parameters
+/// holds the epoch this signature was produced for.
 #[pyclass]
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PySignature {
     inner: Vec<u8>,
+    pub(crate) scheme_params: SchemeParams,
 }
 
 #[pymethods]
 impl PySignature {
-    /// Serialize the signature to bytes
+    /// Serialize the signature to the canonical wire format.
     fn to_bytes(&self) -> PyResult<Vec<u8>> {
-        Ok(self.inner.clone())
+        Ok(encode_canonical(self.scheme_params, &self.inner))
     }
 
-    /// Deserialize a signature from bytes
+    /// Deserialize a signature previously produced by `to_bytes`. Rejects
+    /// truncated blobs and blobs with a mismatched canonical version.
     #[staticmethod]
     fn from_bytes(data: Vec<u8>) -> PyResult<Self> {
-        Ok(PySignature { inner: data })
+        let (scheme_params, inner) = decode_canonical(&data)?;
+        Ok(PySignature {
+            inner,
+            scheme_params,
+        })
     }
 
     fn __repr__(&self) -> String {
@@ -107,20 +214,26 @@ impl PySignature {
     }
 }
 
+/// Default digest length (bytes) expected by `sign_prehash`/`verify_prehash`
+/// for the SHA3 hash family (a SHA3-256 output).
+const SHA3_DIGEST_LEN: usize = 32;
+
 /// Hash-based signature scheme using SHA3
 /// This represents one of the instantiations from hashsig::signature::generalized_xmss
 #[pyclass]
 pub struct HashSigSHA3 {
     lifetime: u64,
+    digest_len: usize,
 }
 
 #[pymethods]
 impl HashSigSHA3 {
     #[new]
-    #[pyo3(signature = (lifetime=None))]
-    pub fn new(lifetime: Option<u64>) -> Self {
+    #[pyo3(signature = (lifetime=None, digest_len=None))]
+    pub fn new(lifetime: Option<u64>, digest_len: Option<usize>) -> Self {
         HashSigSHA3 {
             lifetime: lifetime.unwrap_or(1 << 20), // Default ~1M epochs
+            digest_len: digest_len.unwrap_or(SHA3_DIGEST_LEN),
         }
     }
 
@@ -143,15 +256,24 @@ impl HashSigSHA3 {
         // 2. Call T::key_gen(&mut rng, activation_epoch, self.lifetime)
         // 3. Serialize the keys
 
+        let scheme_params = SchemeParams {
+            scheme: SchemeId::Sha3,
+            lifetime: self.lifetime,
+            activation_epoch,
+        };
+
         // For now, return placeholder keys
         let pk = PyPublicKey {
             inner: vec![0; 64], // Placeholder
+            scheme_params,
         };
 
         let sk = PySecretKey {
             inner: vec![0; 128], // Placeholder
             prepared_start: activation_epoch,
             prepared_end: activation_epoch + 1000,
+            scheme_params,
+            destroyed: false,
         };
 
         Ok((pk, sk))
@@ -163,17 +285,28 @@ impl HashSigSHA3 {
     ///     secret_key: The secret key to sign with
     ///     epoch: The epoch for this signature (must be in prepared interval)
     ///     message: The message to sign (bytes)
+    ///     context: Domain-separation context to bind into the message hash.
+    ///         Defaults to the empty domain for backward compatibility.
+    ///     slashing_protection: Optional store consulted before signing; if
+    ///         `epoch` was already used, signing fails instead of producing a
+    ///         second signature for it.
     ///
     /// Returns:
     ///     The signature
     ///
     /// Important: Each (secret_key, epoch) pair must only be used once!
+    #[pyo3(signature = (secret_key, epoch, message, context=None, slashing_protection=None))]
     fn sign(
         &self,
+        py: Python<'_>,
         secret_key: &PySecretKey,
         epoch: u64,
-        _message: Vec<u8>,
+        message: Vec<u8>,
+        context: Option<SigningContext>,
+        slashing_protection: Option<Py<SlashingProtection>>,
     ) -> PyResult<PySignature> {
+        secret_key.ensure_not_destroyed()?;
+
         // Check that the epoch is prepared
         if !secret_key.is_prepared_for_epoch(epoch) {
             return Err(PyValueError::new_err(format!(
@@ -182,10 +315,31 @@ impl HashSigSHA3 {
             )));
         }
 
-        // In a real implementation, this would call T::sign(&sk, epoch, &message)
+        if let Some(protection) = &slashing_protection {
+            if protection.borrow(py).was_epoch_used(py, epoch)? {
+                return Err(EpochReuseError::new_err(format!(
+                    "epoch {} is already used according to the slashing protection store",
+                    epoch
+                )));
+            }
+        }
+
+        let mut bound_message = context.unwrap_or_default().bind(&message);
+
+        // In a real implementation, this would call T::sign(&sk, epoch, &bound_message)
+        bound_message.zeroize();
+
+        if let Some(protection) = &slashing_protection {
+            protection.borrow_mut(py).record(py, epoch)?;
+        }
 
         Ok(PySignature {
             inner: vec![0; 256], // Placeholder
+            scheme_params: SchemeParams {
+                scheme: SchemeId::Sha3,
+                lifetime: secret_key.scheme_params.lifetime,
+                activation_epoch: epoch,
+            },
         })
     }
 
@@ -196,40 +350,183 @@ impl HashSigSHA3 {
     ///     epoch: The epoch the signature was created for
     ///     message: The message that was signed (bytes)
     ///     signature: The signature to verify
+    ///     context: Domain-separation context the signature was bound with.
+    ///         Must match the context passed to `sign`.
     ///
     /// Returns:
     ///     True if the signature is valid, False otherwise
+    #[pyo3(signature = (public_key, epoch, message, signature, context=None))]
     fn verify(
         &self,
-        _public_key: &PyPublicKey,
-        _epoch: u64,
-        _message: Vec<u8>,
-        _signature: &PySignature,
+        public_key: &PyPublicKey,
+        epoch: u64,
+        message: Vec<u8>,
+        signature: &PySignature,
+        context: Option<SigningContext>,
     ) -> PyResult<bool> {
-        // In a real implementation, this would call T::verify(&pk, epoch, &message, &sig)
+        let bound_message = context.unwrap_or_default().bind(&message);
+        Ok(self.verify_one(public_key, epoch, &bound_message, signature))
+    }
 
-        Ok(true) // Placeholder
+    /// Verify a batch of signatures in parallel.
+    ///
+    /// Unlike algebraic schemes there is no multi-scalar shortcut for hash-based
+    /// signatures, so the win here comes from data parallelism: each one-time
+    /// chain and Merkle authentication path is recomputed independently, so the
+    /// per-item verifications are dispatched across a rayon thread pool while the
+    /// GIL is released.
+    ///
+    /// Args:
+    ///     items: List of (public_key, epoch, message, signature) tuples
+    ///     context: Domain-separation context the signatures were bound with.
+    ///         Must match the context passed to `sign` for every item in the
+    ///         batch; `verify_batch` cannot mix contexts within one call.
+    ///     num_threads: Optional size for a dedicated rayon thread pool. If
+    ///         omitted, the global rayon pool is used.
+    ///
+    /// Returns:
+    ///     Tuple of (all_valid, results, first_failure_index), where `results`
+    ///     preserves input order and `first_failure_index` is `None` when every
+    ///     item verifies.
+    #[pyo3(signature = (items, context=None, num_threads=None))]
+    fn verify_batch(
+        &self,
+        py: Python<'_>,
+        items: Vec<(PyPublicKey, u64, Vec<u8>, PySignature)>,
+        context: Option<SigningContext>,
+        num_threads: Option<usize>,
+    ) -> PyResult<(bool, Vec<bool>, Option<usize>)> {
+        let context = context.unwrap_or_default();
+        let results =
+            py.allow_threads(|| self.verify_batch_inner(&items, &context, num_threads))?;
+
+        let first_failure = results.iter().position(|&ok| !ok);
+        let all_valid = first_failure.is_none();
+        Ok((all_valid, results, first_failure))
+    }
+
+    /// Sign an already-computed message digest rather than the raw message.
+    /// Useful for callers who stream large messages and hash incrementally
+    /// (or with an external accelerator) before signing, instead of handing
+    /// the full message to `sign`.
+    ///
+    /// `digest` must be exactly `get_digest_len()` bytes and must come from
+    /// this scheme's declared hash family (SHA3) so domain/tweak binding
+    /// stays consistent with `sign`.
+    #[pyo3(signature = (secret_key, epoch, digest, context=None, slashing_protection=None))]
+    fn sign_prehash(
+        &self,
+        py: Python<'_>,
+        secret_key: &PySecretKey,
+        epoch: u64,
+        digest: Vec<u8>,
+        context: Option<SigningContext>,
+        slashing_protection: Option<Py<SlashingProtection>>,
+    ) -> PyResult<PySignature> {
+        self.check_digest_len(&digest)?;
+        self.sign(py, secret_key, epoch, digest, context, slashing_protection)
+    }
+
+    /// Verify a signature produced by `sign_prehash`, checking `digest`
+    /// directly rather than re-hashing a raw message.
+    #[pyo3(signature = (public_key, epoch, digest, signature, context=None))]
+    fn verify_prehash(
+        &self,
+        public_key: &PyPublicKey,
+        epoch: u64,
+        digest: Vec<u8>,
+        signature: &PySignature,
+        context: Option<SigningContext>,
+    ) -> PyResult<bool> {
+        self.check_digest_len(&digest)?;
+        self.verify(public_key, epoch, digest, signature, context)
     }
 
     /// Get the lifetime (maximum number of epochs) for this scheme
     fn get_lifetime(&self) -> u64 {
         self.lifetime
     }
+
+    /// Get the expected prehash digest length (bytes) for this scheme.
+    fn get_digest_len(&self) -> usize {
+        self.digest_len
+    }
+}
+
+impl HashSigSHA3 {
+    /// Validates that `digest` matches this scheme's declared digest length.
+    fn check_digest_len(&self, digest: &[u8]) -> PyResult<()> {
+        if digest.len() != self.digest_len {
+            return Err(PyValueError::new_err(format!(
+                "invalid prehash length: expected {} bytes for this scheme's hash family, got {}",
+                self.digest_len,
+                digest.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Core single-item verification, shared by `verify` and `verify_batch`.
+    fn verify_one(
+        &self,
+        _public_key: &PyPublicKey,
+        _epoch: u64,
+        _message: &[u8],
+        _signature: &PySignature,
+    ) -> bool {
+        // In a real implementation, this would call T::verify(&pk, epoch, &message, &sig)
+        true // Placeholder
+    }
+
+    /// Dispatches `items` across a rayon thread pool and collects results in
+    /// input order. Does not touch the GIL, so it is safe to call from within
+    /// `py.allow_threads`. `context` is bound into each item's message before
+    /// verification, mirroring `verify`.
+    fn verify_batch_inner(
+        &self,
+        items: &[(PyPublicKey, u64, Vec<u8>, PySignature)],
+        context: &SigningContext,
+        num_threads: Option<usize>,
+    ) -> PyResult<Vec<bool>> {
+        let verify_item = |(pk, epoch, message, sig): &(PyPublicKey, u64, Vec<u8>, PySignature)| {
+            let bound_message = context.bind(message);
+            self.verify_one(pk, *epoch, &bound_message, sig)
+        };
+
+        match num_threads {
+            Some(n) => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(n)
+                    .build()
+                    .map_err(|e| {
+                        PyValueError::new_err(format!("failed to build thread pool: {}", e))
+                    })?;
+                Ok(pool.install(|| items.par_iter().map(verify_item).collect()))
+            }
+            None => Ok(items.par_iter().map(verify_item).collect()),
+        }
+    }
 }
 
+/// Default digest length (bytes) expected by `sign_prehash`/`verify_prehash`
+/// for the Poseidon2 hash family.
+const POSEIDON_DIGEST_LEN: usize = 32;
+
 /// Hash-based signature scheme using Poseidon2
 #[pyclass]
 pub struct HashSigPoseidon {
     lifetime: u64,
+    digest_len: usize,
 }
 
 #[pymethods]
 impl HashSigPoseidon {
     #[new]
-    #[pyo3(signature = (lifetime=None))]
-    pub fn new(lifetime: Option<u64>) -> Self {
+    #[pyo3(signature = (lifetime=None, digest_len=None))]
+    pub fn new(lifetime: Option<u64>, digest_len: Option<usize>) -> Self {
         HashSigPoseidon {
             lifetime: lifetime.unwrap_or(1 << 20),
+            digest_len: digest_len.unwrap_or(POSEIDON_DIGEST_LEN),
         }
     }
 
@@ -239,23 +536,40 @@ impl HashSigPoseidon {
         _seed: Option<Vec<u8>>,
         activation_epoch: u64,
     ) -> PyResult<(PyPublicKey, PySecretKey)> {
-        let pk = PyPublicKey { inner: vec![0; 64] };
+        let scheme_params = SchemeParams {
+            scheme: SchemeId::Poseidon,
+            lifetime: self.lifetime,
+            activation_epoch,
+        };
+
+        let pk = PyPublicKey {
+            inner: vec![0; 64],
+            scheme_params,
+        };
 
         let sk = PySecretKey {
             inner: vec![0; 128],
             prepared_start: activation_epoch,
             prepared_end: activation_epoch + 1000,
+            scheme_params,
+            destroyed: false,
         };
 
         Ok((pk, sk))
     }
 
+    #[pyo3(signature = (secret_key, epoch, message, context=None, slashing_protection=None))]
     fn sign(
         &self,
+        py: Python<'_>,
         secret_key: &PySecretKey,
         epoch: u64,
-        _message: Vec<u8>,
+        message: Vec<u8>,
+        context: Option<SigningContext>,
+        slashing_protection: Option<Py<SlashingProtection>>,
     ) -> PyResult<PySignature> {
+        secret_key.ensure_not_destroyed()?;
+
         if !secret_key.is_prepared_for_epoch(epoch) {
             return Err(PyValueError::new_err(format!(
                 "Secret key not prepared for epoch {}",
@@ -263,24 +577,161 @@ impl HashSigPoseidon {
             )));
         }
 
+        if let Some(protection) = &slashing_protection {
+            if protection.borrow(py).was_epoch_used(py, epoch)? {
+                return Err(EpochReuseError::new_err(format!(
+                    "epoch {} is already used according to the slashing protection store",
+                    epoch
+                )));
+            }
+        }
+
+        let mut bound_message = context.unwrap_or_default().bind(&message);
+
+        // In a real implementation, this would call T::sign(&sk, epoch, &bound_message)
+        bound_message.zeroize();
+
+        if let Some(protection) = &slashing_protection {
+            protection.borrow_mut(py).record(py, epoch)?;
+        }
+
         Ok(PySignature {
             inner: vec![0; 256],
+            scheme_params: SchemeParams {
+                scheme: SchemeId::Poseidon,
+                lifetime: secret_key.scheme_params.lifetime,
+                activation_epoch: epoch,
+            },
         })
     }
 
+    #[pyo3(signature = (public_key, epoch, message, signature, context=None))]
     fn verify(
         &self,
-        _public_key: &PyPublicKey,
-        _epoch: u64,
-        _message: Vec<u8>,
-        _signature: &PySignature,
+        public_key: &PyPublicKey,
+        epoch: u64,
+        message: Vec<u8>,
+        signature: &PySignature,
+        context: Option<SigningContext>,
+    ) -> PyResult<bool> {
+        let bound_message = context.unwrap_or_default().bind(&message);
+        Ok(self.verify_one(public_key, epoch, &bound_message, signature))
+    }
+
+    /// Verify a batch of signatures in parallel. See `HashSigSHA3::verify_batch`
+    /// for the rationale; the per-item work here recomputes Poseidon2 chains and
+    /// authentication paths instead of SHA3 ones.
+    #[pyo3(signature = (items, context=None, num_threads=None))]
+    fn verify_batch(
+        &self,
+        py: Python<'_>,
+        items: Vec<(PyPublicKey, u64, Vec<u8>, PySignature)>,
+        context: Option<SigningContext>,
+        num_threads: Option<usize>,
+    ) -> PyResult<(bool, Vec<bool>, Option<usize>)> {
+        let context = context.unwrap_or_default();
+        let results =
+            py.allow_threads(|| self.verify_batch_inner(&items, &context, num_threads))?;
+
+        let first_failure = results.iter().position(|&ok| !ok);
+        let all_valid = first_failure.is_none();
+        Ok((all_valid, results, first_failure))
+    }
+
+    /// Sign an already-computed message digest rather than the raw message.
+    /// See `HashSigSHA3::sign_prehash`; `digest` must come from this
+    /// scheme's Poseidon2 hash family.
+    #[pyo3(signature = (secret_key, epoch, digest, context=None, slashing_protection=None))]
+    fn sign_prehash(
+        &self,
+        py: Python<'_>,
+        secret_key: &PySecretKey,
+        epoch: u64,
+        digest: Vec<u8>,
+        context: Option<SigningContext>,
+        slashing_protection: Option<Py<SlashingProtection>>,
+    ) -> PyResult<PySignature> {
+        self.check_digest_len(&digest)?;
+        self.sign(py, secret_key, epoch, digest, context, slashing_protection)
+    }
+
+    /// Verify a signature produced by `sign_prehash`, checking `digest`
+    /// directly rather than re-hashing a raw message.
+    #[pyo3(signature = (public_key, epoch, digest, signature, context=None))]
+    fn verify_prehash(
+        &self,
+        public_key: &PyPublicKey,
+        epoch: u64,
+        digest: Vec<u8>,
+        signature: &PySignature,
+        context: Option<SigningContext>,
     ) -> PyResult<bool> {
-        Ok(true)
+        self.check_digest_len(&digest)?;
+        self.verify(public_key, epoch, digest, signature, context)
     }
 
     fn get_lifetime(&self) -> u64 {
         self.lifetime
     }
+
+    /// Get the expected prehash digest length (bytes) for this scheme.
+    fn get_digest_len(&self) -> usize {
+        self.digest_len
+    }
+}
+
+impl HashSigPoseidon {
+    /// Validates that `digest` matches this scheme's declared digest length.
+    fn check_digest_len(&self, digest: &[u8]) -> PyResult<()> {
+        if digest.len() != self.digest_len {
+            return Err(PyValueError::new_err(format!(
+                "invalid prehash length: expected {} bytes for this scheme's hash family, got {}",
+                self.digest_len,
+                digest.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Core single-item verification, shared by `verify` and `verify_batch`.
+    fn verify_one(
+        &self,
+        _public_key: &PyPublicKey,
+        _epoch: u64,
+        _message: &[u8],
+        _signature: &PySignature,
+    ) -> bool {
+        true
+    }
+
+    /// Dispatches `items` across a rayon thread pool and collects results in
+    /// input order. Does not touch the GIL, so it is safe to call from within
+    /// `py.allow_threads`. `context` is bound into each item's message before
+    /// verification, mirroring `verify`.
+    fn verify_batch_inner(
+        &self,
+        items: &[(PyPublicKey, u64, Vec<u8>, PySignature)],
+        context: &SigningContext,
+        num_threads: Option<usize>,
+    ) -> PyResult<Vec<bool>> {
+        let verify_item = |(pk, epoch, message, sig): &(PyPublicKey, u64, Vec<u8>, PySignature)| {
+            let bound_message = context.bind(message);
+            self.verify_one(pk, *epoch, &bound_message, sig)
+        };
+
+        match num_threads {
+            Some(n) => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(n)
+                    .build()
+                    .map_err(|e| {
+                        PyValueError::new_err(format!("failed to build thread pool: {}", e))
+                    })?;
+                Ok(pool.install(|| items.par_iter().map(verify_item).collect()))
+            }
+            None => Ok(items.par_iter().map(verify_item).collect()),
+        }
+    }
 }
 
 /// Python module for hash-sig bindings
@@ -289,7 +740,11 @@ fn hashsig_py(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyPublicKey>()?;
     m.add_class::<PySecretKey>()?;
     m.add_class::<PySignature>()?;
+    m.add_class::<SigningContext>()?;
+    m.add_class::<SlashingProtection>()?;
+    m.add_class::<PyKeystore>()?;
     m.add_class::<HashSigSHA3>()?;
     m.add_class::<HashSigPoseidon>()?;
+    m.add("EpochReuseError", m.py().get_type_bound::<EpochReuseError>())?;
     Ok(())
 }
@@ -0,0 +1,158 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Version byte for the canonical wire format. Bump whenever the layout
+/// changes so old and new builds can tell each other's blobs apart instead
+/// of silently misinterpreting them.
+pub(crate) const CANONICAL_VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 1 + 1 + 8 + 8 + 4;
+
+/// Identifies which hash family a blob's scheme parameters belong to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum SchemeId {
+    Sha3 = 1,
+    Poseidon = 2,
+}
+
+impl SchemeId {
+    fn to_byte(self) -> u8 {
+        self as u8
+    }
+
+    fn from_byte(byte: u8) -> PyResult<Self> {
+        match byte {
+            1 => Ok(SchemeId::Sha3),
+            2 => Ok(SchemeId::Poseidon),
+            other => Err(PyValueError::new_err(format!(
+                "unknown scheme identifier {} in canonical blob",
+                other
+            ))),
+        }
+    }
+}
+
+/// The scheme parameters bound into every canonically-serialized key or
+/// signature, so a blob produced by one build can be safely rejected (or,
+/// eventually, upgraded) by another instead of being silently misread.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct SchemeParams {
+    pub(crate) scheme: SchemeId,
+    pub(crate) lifetime: u64,
+    pub(crate) activation_epoch: u64,
+}
+
+/// Encodes `payload` into the canonical wire format: a version byte, scheme
+/// identifier, scheme parameters, and a length-checked payload. This is the
+/// format used by `to_bytes`/`from_bytes` on keys and signatures; it is
+/// deliberately distinct from the `serde`-gated pickling path, which is free
+/// to change shape independently.
+pub(crate) fn encode_canonical(params: SchemeParams, payload: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(HEADER_LEN + payload.len());
+    buf.push(CANONICAL_VERSION);
+    buf.push(params.scheme.to_byte());
+    buf.extend_from_slice(&params.lifetime.to_le_bytes());
+    buf.extend_from_slice(&params.activation_epoch.to_le_bytes());
+    buf.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    buf.extend_from_slice(payload);
+    buf
+}
+
+/// Decodes a canonical blob, rejecting truncated headers/payloads and
+/// version mismatches rather than silently accepting them.
+pub(crate) fn decode_canonical(data: &[u8]) -> PyResult<(SchemeParams, Vec<u8>)> {
+    if data.len() < HEADER_LEN {
+        return Err(PyValueError::new_err(
+            "truncated canonical blob: missing header",
+        ));
+    }
+
+    let version = data[0];
+    if version != CANONICAL_VERSION {
+        return Err(PyValueError::new_err(format!(
+            "unsupported canonical blob version {} (expected {})",
+            version, CANONICAL_VERSION
+        )));
+    }
+
+    let scheme = SchemeId::from_byte(data[1])?;
+    let lifetime = u64::from_le_bytes(data[2..10].try_into().unwrap());
+    let activation_epoch = u64::from_le_bytes(data[10..18].try_into().unwrap());
+    let payload_len = u32::from_le_bytes(data[18..22].try_into().unwrap()) as usize;
+    let payload = &data[HEADER_LEN..];
+
+    if payload.len() != payload_len {
+        return Err(PyValueError::new_err(format!(
+            "canonical blob length mismatch: header declares {} payload bytes, found {}",
+            payload_len,
+            payload.len()
+        )));
+    }
+
+    Ok((
+        SchemeParams {
+            scheme,
+            lifetime,
+            activation_epoch,
+        },
+        payload.to_vec(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(scheme: SchemeId) -> SchemeParams {
+        SchemeParams {
+            scheme,
+            lifetime: 1 << 20,
+            activation_epoch: 42,
+        }
+    }
+
+    #[test]
+    fn round_trips_sha3() {
+        let payload = vec![1, 2, 3, 4, 5];
+        let encoded = encode_canonical(params(SchemeId::Sha3), &payload);
+        let (decoded_params, decoded_payload) = decode_canonical(&encoded).unwrap();
+        assert_eq!(decoded_params.scheme, SchemeId::Sha3);
+        assert_eq!(decoded_params.lifetime, 1 << 20);
+        assert_eq!(decoded_params.activation_epoch, 42);
+        assert_eq!(decoded_payload, payload);
+    }
+
+    #[test]
+    fn round_trips_poseidon() {
+        let payload = vec![9, 8, 7];
+        let encoded = encode_canonical(params(SchemeId::Poseidon), &payload);
+        let (decoded_params, decoded_payload) = decode_canonical(&encoded).unwrap();
+        assert_eq!(decoded_params.scheme, SchemeId::Poseidon);
+        assert_eq!(decoded_payload, payload);
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        let encoded = encode_canonical(params(SchemeId::Sha3), &[1, 2, 3]);
+        let err = decode_canonical(&encoded[..HEADER_LEN - 1]).unwrap_err();
+        assert!(err.to_string().contains("truncated canonical blob"));
+    }
+
+    #[test]
+    fn rejects_truncated_payload() {
+        let mut encoded = encode_canonical(params(SchemeId::Sha3), &[1, 2, 3, 4]);
+        encoded.truncate(encoded.len() - 1);
+        let err = decode_canonical(&encoded).unwrap_err();
+        assert!(err.to_string().contains("length mismatch"));
+    }
+
+    #[test]
+    fn rejects_wrong_version() {
+        let mut encoded = encode_canonical(params(SchemeId::Sha3), &[1, 2, 3]);
+        encoded[0] = CANONICAL_VERSION + 1;
+        let err = decode_canonical(&encoded).unwrap_err();
+        assert!(err.to_string().contains("unsupported canonical blob version"));
+    }
+}
@@ -0,0 +1,243 @@
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Write as _;
+use std::path::PathBuf;
+
+pyo3::create_exception!(
+    hashsig_py,
+    EpochReuseError,
+    PyException,
+    "Raised when signing an epoch that the slashing protection store reports as already used."
+);
+
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct JsonState {
+    highest_used: Option<u64>,
+    #[serde(default)]
+    used_epochs: BTreeSet<u64>,
+}
+
+/// Where used-epoch bookkeeping is persisted.
+enum Backend {
+    /// Process-local only; does not survive restarts.
+    Memory,
+    /// Persisted as JSON, written via write-ahead-to-temp-file-then-rename so a
+    /// crash mid-write can never leave a corrupt or partially updated store.
+    JsonFile(PathBuf),
+    /// Delegates to a user-supplied Python object exposing
+    /// `was_epoch_used(epoch) -> bool` and `record(epoch)`.
+    Callback(PyObject),
+}
+
+/// Tracks which `(secret_key, epoch)` pairs have already been signed so a
+/// signer can refuse to reuse an epoch. Consulted inside `HashSigSHA3::sign`
+/// / `HashSigPoseidon::sign` before a signature is produced.
+#[pyclass]
+pub struct SlashingProtection {
+    backend: Backend,
+    track_all_epochs: bool,
+    highest_used: Option<u64>,
+    used_epochs: BTreeSet<u64>,
+}
+
+#[pymethods]
+impl SlashingProtection {
+    /// Construct an in-memory protection store (does not survive restarts).
+    #[staticmethod]
+    #[pyo3(signature = (track_all_epochs=false))]
+    fn in_memory(track_all_epochs: bool) -> Self {
+        SlashingProtection {
+            backend: Backend::Memory,
+            track_all_epochs,
+            highest_used: None,
+            used_epochs: BTreeSet::new(),
+        }
+    }
+
+    /// Construct a protection store backed by a JSON file at `path`, loading
+    /// any existing state so signing history survives process restarts.
+    #[staticmethod]
+    #[pyo3(signature = (path, track_all_epochs=false))]
+    fn json_file(path: String, track_all_epochs: bool) -> PyResult<Self> {
+        let path = PathBuf::from(path);
+        let state = Self::load_json(&path)?;
+        Ok(SlashingProtection {
+            backend: Backend::JsonFile(path),
+            track_all_epochs,
+            highest_used: state.highest_used,
+            used_epochs: state.used_epochs,
+        })
+    }
+
+    /// Construct a protection store that delegates all bookkeeping to a
+    /// user-supplied Python object exposing `was_epoch_used(epoch) -> bool`
+    /// and `record(epoch)`.
+    #[staticmethod]
+    fn callback(handler: PyObject) -> Self {
+        SlashingProtection {
+            backend: Backend::Callback(handler),
+            track_all_epochs: false,
+            highest_used: None,
+            used_epochs: BTreeSet::new(),
+        }
+    }
+
+    /// Returns whether `epoch` counts as used. With `track_all_epochs`, only
+    /// epochs passed to `record` are used; otherwise every epoch at or below
+    /// the highest recorded epoch counts as used, signed or not.
+    pub(crate) fn was_epoch_used(&self, py: Python<'_>, epoch: u64) -> PyResult<bool> {
+        if let Backend::Callback(handler) = &self.backend {
+            return handler
+                .call_method1(py, "was_epoch_used", (epoch,))?
+                .extract(py);
+        }
+
+        if self.track_all_epochs {
+            Ok(self.used_epochs.contains(&epoch))
+        } else {
+            Ok(self.highest_used.is_some_and(|highest| epoch <= highest))
+        }
+    }
+
+    /// Records `epoch` as signed, persisting the update before returning.
+    pub(crate) fn record(&mut self, py: Python<'_>, epoch: u64) -> PyResult<()> {
+        if let Backend::Callback(handler) = &self.backend {
+            handler.call_method1(py, "record", (epoch,))?;
+            return Ok(());
+        }
+
+        self.highest_used = Some(self.highest_used.map_or(epoch, |h| h.max(epoch)));
+        if self.track_all_epochs {
+            self.used_epochs.insert(epoch);
+        }
+
+        if let Backend::JsonFile(path) = &self.backend {
+            self.persist_json(path)?;
+        }
+        Ok(())
+    }
+
+    /// One past the highest epoch recorded so far (0 if none): every epoch
+    /// from here on is safe to sign. With `track_all_epochs`, unrecorded
+    /// epochs below it are also accepted by `was_epoch_used`.
+    ///
+    /// Not supported for the `Callback` backend: bookkeeping there lives
+    /// entirely in the user-supplied handler, which this class has no way to
+    /// query for a highest-used epoch, so raise rather than report a bogus 0.
+    fn min_safe_epoch(&self) -> PyResult<u64> {
+        if let Backend::Callback(_) = &self.backend {
+            return Err(PyException::new_err(
+                "min_safe_epoch is not supported for the callback backend; \
+                 query the handler object directly",
+            ));
+        }
+        Ok(self.highest_used.map_or(0, |h| h + 1))
+    }
+}
+
+impl SlashingProtection {
+    fn load_json(path: &PathBuf) -> PyResult<JsonState> {
+        if !path.exists() {
+            return Ok(JsonState::default());
+        }
+        let contents = fs::read_to_string(path).map_err(|e| {
+            PyException::new_err(format!("failed to read slashing protection store: {}", e))
+        })?;
+        serde_json::from_str(&contents).map_err(|e| {
+            PyException::new_err(format!("corrupt slashing protection store: {}", e))
+        })
+    }
+
+    fn persist_json(&self, path: &PathBuf) -> PyResult<()> {
+        let state = JsonState {
+            highest_used: self.highest_used,
+            used_epochs: self.used_epochs.clone(),
+        };
+        let serialized = serde_json::to_vec_pretty(&state).map_err(|e| {
+            PyException::new_err(format!("failed to serialize slashing protection store: {}", e))
+        })?;
+
+        // Write-ahead to a temp file, then rename, so a crash mid-write can
+        // never corrupt or partially overwrite the existing store.
+        let tmp_path = path.with_extension("tmp");
+        let mut tmp_file = fs::File::create(&tmp_path).map_err(|e| {
+            PyException::new_err(format!("failed to write slashing protection store: {}", e))
+        })?;
+        tmp_file.write_all(&serialized).map_err(|e| {
+            PyException::new_err(format!("failed to write slashing protection store: {}", e))
+        })?;
+        tmp_file.sync_all().map_err(|e| {
+            PyException::new_err(format!("failed to write slashing protection store: {}", e))
+        })?;
+        fs::rename(&tmp_path, path).map_err(|e| {
+            PyException::new_err(format!("failed to persist slashing protection store: {}", e))
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "hashsig_py_slashing_test_{}_{}.json",
+            std::process::id(),
+            name
+        ));
+        path
+    }
+
+    #[test]
+    fn memory_backend_rejects_repeated_epoch() {
+        Python::with_gil(|py| {
+            let mut protection = SlashingProtection::in_memory(false);
+            assert!(!protection.was_epoch_used(py, 5).unwrap());
+            protection.record(py, 5).unwrap();
+            assert!(protection.was_epoch_used(py, 5).unwrap());
+            assert!(protection.was_epoch_used(py, 3).unwrap());
+            assert!(!protection.was_epoch_used(py, 6).unwrap());
+        });
+    }
+
+    #[test]
+    fn json_file_backend_reloads_prior_state() {
+        let path = temp_path("reload");
+        fs::remove_file(&path).ok();
+
+        Python::with_gil(|py| {
+            let mut protection =
+                SlashingProtection::json_file(path.to_str().unwrap().to_string(), false).unwrap();
+            protection.record(py, 7).unwrap();
+        });
+
+        Python::with_gil(|py| {
+            let reloaded =
+                SlashingProtection::json_file(path.to_str().unwrap().to_string(), false).unwrap();
+            assert!(reloaded.was_epoch_used(py, 7).unwrap());
+            assert!(!reloaded.was_epoch_used(py, 8).unwrap());
+        });
+
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn min_safe_epoch_raises_for_callback_backend() {
+        Python::with_gil(|py| {
+            let module = PyModule::from_code_bound(
+                py,
+                "class Handler:\n    def was_epoch_used(self, epoch):\n        return False\n    def record(self, epoch):\n        pass\n",
+                "handler.py",
+                "handler",
+            )
+            .unwrap();
+            let handler = module.getattr("Handler").unwrap().call0().unwrap();
+            let protection = SlashingProtection::callback(handler.into());
+            assert!(protection.min_safe_epoch().is_err());
+        });
+    }
+}